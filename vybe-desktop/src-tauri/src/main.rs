@@ -3,25 +3,174 @@
 use tauri::{
     Manager, WindowEvent, State, GlobalShortcutManager
 };
-use std::sync::Mutex;
-use std::process::{Command, Child};
+use shared_child::SharedChild;
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::process::{Command, Stdio};
+use std::path::PathBuf;
+
+// `taskkill` without `/F` only works by posting WM_CLOSE to a GUI window's
+// message loop, so it's a no-op against a headless console process like our
+// Python backend. The real polite-shutdown primitive for a console process
+// is CTRL_BREAK_EVENT, which requires the child to live in its own process
+// group (hence `CREATE_NEW_PROCESS_GROUP` at spawn time) and isn't wrapped
+// by std, so we declare it ourselves the same way `libc::kill` is used
+// directly on Unix below.
+#[cfg(target_os = "windows")]
+const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+#[cfg(target_os = "windows")]
+const CTRL_BREAK_EVENT: u32 = 1;
+#[cfg(target_os = "windows")]
+extern "system" {
+    fn GenerateConsoleCtrlEvent(dw_ctrl_event: u32, dw_process_group_id: u32) -> i32;
+}
+
+// Lowest CPython version we consider usable; anything older (e.g. a stray
+// Python 2 on PATH) is rejected by `find_python`.
+const MIN_PYTHON_VERSION: (u32, u32) = (3, 11);
+
+// How long to wait for `<candidate> --version` before giving up on it.
+const PYTHON_PROBE_TIMEOUT_MS: u64 = 2_000;
+
+// How long to wait for a polite shutdown before escalating to SIGKILL.
+const SHUTDOWN_TIMEOUT_MS: u64 = 5_000;
+const SHUTDOWN_POLL_INTERVAL_MS: u64 = 10;
+
+// How many lines of backend output to retain for late-opened log panels.
+const LOG_BUFFER_CAPACITY: usize = 1000;
+
+// Auto-restart backoff: 1s, 2s, 4s... capped at 30s, giving up after 5 tries.
+const RESTART_INITIAL_BACKOFF_SECS: u64 = 1;
+const RESTART_MAX_BACKOFF_SECS: u64 = 30;
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+
+// Port reported before any backend has been started, and the one handed to
+// the backend when binding an ephemeral port fails.
+const DEFAULT_BACKEND_PORT: u16 = 8000;
+
+#[derive(Clone, serde::Serialize)]
+struct LogLine {
+    stream: String,
+    text: String,
+    ts: u128,
+}
 
 // State to track if Python backend is running
 struct BackendState {
-    process: Mutex<Option<Child>>,
+    process: Mutex<Option<Arc<SharedChild>>>,
+    logs: Mutex<VecDeque<LogLine>>,
+    // Set while a shutdown/restart is in progress so the supervisor thread
+    // knows an exit was intentional and shouldn't trigger auto-restart.
+    shutting_down: AtomicBool,
+    // How the currently running backend was started: "system", "bundled",
+    // "rustpython", or "none" if every attempt failed and nothing is
+    // running. Surfaced to the frontend via `get_system_info`.
+    python_mode: Mutex<String>,
+    // Ephemeral port the current backend was handed via VYBE_PORT, so
+    // multiple Vybe windows/instances don't collide on a hardcoded port.
+    port: Mutex<u16>,
 }
 
 impl Drop for BackendState {
     fn drop(&mut self) {
-        if let Some(mut process) = self.process.lock().unwrap().take() {
+        // Mark this as intentional so the supervisor thread doesn't treat
+        // the exit below as a crash and kick off an auto-restart.
+        self.shutting_down.store(true, Ordering::SeqCst);
+
+        // Clone the handle rather than `take()`-ing it so it stays visible
+        // in `process` for the whole (possibly multi-second) termination
+        // wait; only cleared once we know the child is actually gone.
+        let process = self.process.lock().unwrap().clone();
+        if let Some(process) = process {
             println!("Shutting down Python backend...");
-            let _ = process.kill();
-            let _ = process.wait();
+            terminate_child(&process, SHUTDOWN_TIMEOUT_MS);
+            *self.process.lock().unwrap() = None;
             println!("Python backend shut down.");
         }
     }
 }
 
+// Terminate a child process gracefully: send a polite termination signal
+// (SIGTERM on Unix, CTRL_BREAK_EVENT on Windows) and poll `try_wait()` until it
+// exits or `timeout_ms` elapses, then escalate to SIGKILL/`taskkill /F`.
+// A forced termination always finishes with a blocking `wait()`; on the
+// graceful path `try_wait()` has already reaped the child, so no zombie is
+// left behind regardless of which path it exited through.
+fn terminate_child(process: &SharedChild, timeout_ms: u64) {
+    let pid = process.id();
+
+    // The child must have been spawned with `CREATE_NEW_PROCESS_GROUP` (see
+    // `start_backend`) for this to reach it instead of us - a
+    // CTRL_BREAK_EVENT sent to our own process group would hit this process
+    // too. `GenerateConsoleCtrlEvent` additionally requires the caller to
+    // share a console with the target, which a release build of this app
+    // (a GUI-subsystem binary, see `windows_subsystem` at the top of this
+    // file) never has - so it reliably fails on exactly the builds we ship.
+    // Check the return value and skip straight to force-termination instead
+    // of silently burning the full `timeout_ms` waiting for a signal that
+    // was never delivered.
+    #[cfg(target_os = "windows")]
+    let signal_delivered = unsafe { GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, pid) != 0 };
+
+    #[cfg(not(target_os = "windows"))]
+    let signal_delivered = {
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGTERM);
+        }
+        true
+    };
+
+    if !signal_delivered {
+        eprintln!(
+            "Process {} has no console to deliver CTRL_BREAK_EVENT to; forcing termination immediately",
+            pid
+        );
+    }
+
+    let start = std::time::Instant::now();
+    let timeout = std::time::Duration::from_millis(timeout_ms);
+    while signal_delivered {
+        match process.try_wait() {
+            Ok(Some(_)) => {
+                println!("Process {} exited gracefully", pid);
+                return;
+            }
+            Ok(None) => {
+                if start.elapsed() >= timeout {
+                    break;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(SHUTDOWN_POLL_INTERVAL_MS));
+            }
+            Err(e) => {
+                eprintln!("Error polling process {}: {}", pid, e);
+                break;
+            }
+        }
+    }
+
+    if signal_delivered {
+        println!("Process {} did not exit within {}ms, forcing termination...", pid, timeout_ms);
+    } else {
+        println!("Forcing termination of process {}...", pid);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let _ = Command::new("taskkill")
+            .args(&["/F", "/T", "/PID", &pid.to_string()])
+            .output();
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = process.kill();
+    }
+
+    let _ = process.wait();
+}
+
 // Commands for frontend
 #[tauri::command]
 fn show_notification(app: tauri::AppHandle, title: String, body: String) {
@@ -32,28 +181,35 @@ fn show_notification(app: tauri::AppHandle, title: String, body: String) {
 }
 
 #[tauri::command]
-fn get_system_info() -> serde_json::Value {
+fn get_system_info(backend_state: State<BackendState>) -> serde_json::Value {
     serde_json::json!({
         "platform": std::env::consts::OS,
         "arch": std::env::consts::ARCH,
         "family": std::env::consts::FAMILY,
+        // "system" | "bundled" | "rustpython" | "none" - lets the frontend
+        // warn when running on the embedded interpreter (which may lack C
+        // extensions) or when every startup attempt failed and nothing is
+        // actually running.
+        "python_mode": *backend_state.python_mode.lock().unwrap(),
     })
 }
 
 #[tauri::command]
-async fn check_backend_status() -> Result<bool, String> {
+async fn check_backend_status(backend_state: State<'_, BackendState>) -> Result<bool, String> {
     let client = reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(5))
         .build()
         .unwrap_or_default();
-    
+
+    let port = *backend_state.port.lock().unwrap();
+
     // Try multiple health check endpoints
     let endpoints = vec![
-        "http://127.0.0.1:8000/health",
-        "http://127.0.0.1:8000/api/status",
-        "http://127.0.0.1:8000/"
+        format!("http://127.0.0.1:{}/health", port),
+        format!("http://127.0.0.1:{}/api/status", port),
+        format!("http://127.0.0.1:{}/", port),
     ];
-    
+
     for endpoint in endpoints {
         match client.get(endpoint).send().await {
             Ok(response) => {
@@ -78,38 +234,261 @@ fn shutdown_app(backend_state: State<BackendState>, app: tauri::AppHandle) {
 }
 
 #[tauri::command]
-async fn get_backend_logs() -> String {
-    // This could be expanded to read actual log files
-    "Backend logs would be displayed here".to_string()
+fn get_backend_logs(backend_state: State<BackendState>) -> Vec<LogLine> {
+    // Backfill history for a freshly opened log panel; live lines arrive
+    // separately via the "backend-log" event.
+    backend_state.logs.lock().unwrap().iter().cloned().collect()
+}
+
+#[tauri::command]
+fn clear_backend_logs(backend_state: State<BackendState>) {
+    backend_state.logs.lock().unwrap().clear();
 }
 
 #[tauri::command] 
-async fn restart_backend(backend_state: State<'_, BackendState>) -> Result<bool, String> {
-    // Shutdown existing backend
-    if let Some(mut process) = backend_state.process.lock().unwrap().take() {
+async fn restart_backend(backend_state: State<'_, BackendState>, app_handle: tauri::AppHandle) -> Result<bool, String> {
+    // Tell the supervisor this exit is intentional so it doesn't race in
+    // with its own auto-restart while we start the replacement below.
+    backend_state.shutting_down.store(true, Ordering::SeqCst);
+
+    // Shutdown existing backend. Clone the handle instead of taking it so
+    // it's still visible to any concurrent reader while we wait on it.
+    let existing = backend_state.process.lock().unwrap().clone();
+    if let Some(process) = existing {
         println!("Shutting down existing backend...");
-        let _ = process.kill();
-        let _ = process.wait();
+        terminate_child(&process, SHUTDOWN_TIMEOUT_MS);
+        *backend_state.process.lock().unwrap() = None;
     }
-    
+
     // Wait a moment for cleanup
     tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-    
+
     // Start new backend
-    let new_process = start_backend();
+    let new_process = start_backend(&app_handle);
+    backend_state.shutting_down.store(false, Ordering::SeqCst);
     *backend_state.process.lock().unwrap() = new_process;
     
     // Check if backend started successfully
     tokio::time::sleep(std::time::Duration::from_secs(5)).await;
-    match check_backend_status().await {
+    match check_backend_status(backend_state).await {
         Ok(status) => Ok(status),
         Err(e) => Err(format!("Failed to check backend status: {}", e))
     }
 }
 
+#[tauri::command]
+fn get_backend_port(backend_state: State<BackendState>) -> u16 {
+    *backend_state.port.lock().unwrap()
+}
+
+
+// Parse the `Python X.Y.Z` (or `Python X.Y.Z+`) string that `python --version`
+// prints, returning (major, minor).
+fn parse_python_version(output: &str) -> Option<(u32, u32)> {
+    let version = output.trim().strip_prefix("Python ")?;
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+// Spawn `candidate --version` and check it meets MIN_PYTHON_VERSION.
+// Python 2 prints its version to stderr, Python 3 to stdout, so check both.
+// Bounded by PYTHON_PROBE_TIMEOUT_MS so a hung shim (e.g. a network-backed
+// pyenv/asdf wrapper) can't block `start_backend()` forever - it's called
+// synchronously from Tauri's `.setup()`.
+fn probe_python_version(candidate: &PathBuf) -> Option<(u32, u32)> {
+    let mut child = Command::new(candidate)
+        .arg("--version")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .ok()?;
+
+    let start = std::time::Instant::now();
+    let timeout = std::time::Duration::from_millis(PYTHON_PROBE_TIMEOUT_MS);
+    let output = loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break child.wait_with_output().ok()?,
+            Ok(None) => {
+                if start.elapsed() >= timeout {
+                    eprintln!("Timed out probing {} --version, skipping", candidate.display());
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return None;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(SHUTDOWN_POLL_INTERVAL_MS));
+            }
+            Err(_) => return None,
+        }
+    };
+
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let version = parse_python_version(&combined)?;
+    if version >= MIN_PYTHON_VERSION {
+        Some(version)
+    } else {
+        None
+    }
+}
+
+// Walk PATH looking for a usable system Python, mirroring the priority a
+// typical launcher (e.g. rustup's `x`) uses: prefer a bare `python`, then
+// `python3`, then `python2`, skipping anything below MIN_PYTHON_VERSION.
+fn find_python() -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    let exe_suffix = if cfg!(target_os = "windows") { ".exe" } else { "" };
+    let names = ["python", "python3", "python2"];
+
+    for name in names {
+        for dir in std::env::split_paths(&path_var) {
+            let candidate = dir.join(format!("{}{}", name, exe_suffix));
+            if !candidate.is_file() {
+                continue;
+            }
+            if let Some(version) = probe_python_version(&candidate) {
+                println!(
+                    "Found system Python {}.{} at {}",
+                    version.0,
+                    version.1,
+                    candidate.display()
+                );
+                return Some(candidate);
+            }
+        }
+    }
+
+    None
+}
+
+// Push a line of backend output into the ring buffer and emit it live to
+// the webview so an open log panel updates in real time.
+fn record_log_line(app_handle: &tauri::AppHandle, stream: &str, text: String) {
+    let line = LogLine {
+        stream: stream.to_string(),
+        text,
+        ts: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0),
+    };
+
+    let backend_state = app_handle.state::<BackendState>();
+    {
+        let mut logs = backend_state.logs.lock().unwrap();
+        if logs.len() >= LOG_BUFFER_CAPACITY {
+            logs.pop_front();
+        }
+        logs.push_back(line.clone());
+    }
+
+    let _ = app_handle.emit_all("backend-log", line);
+}
+
+// Spawn a thread that reads `reader` line-by-line and records each line
+// under `stream` ("stdout"/"stderr") until the pipe closes.
+fn spawn_log_reader<R: std::io::Read + Send + 'static>(
+    reader: R,
+    stream: &'static str,
+    app_handle: tauri::AppHandle,
+) {
+    std::thread::spawn(move || {
+        let mut buf_reader = BufReader::new(reader);
+        let mut raw = Vec::new();
+        loop {
+            raw.clear();
+            // Read raw bytes rather than `BufRead::lines()`: `lines()` bails
+            // out (and kills this thread) on the first non-UTF-8 byte
+            // sequence a child ever writes, silently ending log capture for
+            // the rest of the process's life. `from_utf8_lossy` degrades a
+            // bad line instead of losing the whole stream.
+            match buf_reader.read_until(b'\n', &mut raw) {
+                Ok(0) => break,
+                Ok(_) => {
+                    while raw.last() == Some(&b'\n') || raw.last() == Some(&b'\r') {
+                        raw.pop();
+                    }
+                    let text = String::from_utf8_lossy(&raw).into_owned();
+                    record_log_line(&app_handle, stream, text);
+                }
+                Err(_) => break,
+            }
+        }
+    });
+}
+
+// Block on the child's exit in a dedicated thread. If it dies while we're
+// not in the middle of an intentional shutdown/restart, treat it as a
+// crash and hand off to the auto-restart loop.
+fn spawn_supervisor(app_handle: tauri::AppHandle, child: Arc<SharedChild>) {
+    std::thread::spawn(move || {
+        let status = child.wait();
+
+        let backend_state = app_handle.state::<BackendState>();
+
+        // `shutting_down` alone isn't enough: it can be reset to false by a
+        // `restart_backend` that already installed a new child before this
+        // thread wakes up from `wait()`. Check that `process` still points
+        // at the exact child we were watching; if it doesn't, a newer
+        // generation is already running and this exit is stale, not a crash.
+        let is_current = backend_state
+            .process
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map_or(false, |current| Arc::ptr_eq(current, &child));
+        if !is_current || backend_state.shutting_down.load(Ordering::SeqCst) {
+            return;
+        }
+
+        eprintln!("Python backend exited unexpectedly: {:?}", status);
+        let _ = app_handle.emit_all(
+            "backend-crashed",
+            status.map(|s| s.to_string()).unwrap_or_else(|e| e.to_string()),
+        );
+
+        attempt_auto_restart(app_handle);
+    });
+}
+
+// Retry `start_backend` with exponential backoff (capped) up to
+// MAX_RESTART_ATTEMPTS, emitting status events the UI can show.
+fn attempt_auto_restart(app_handle: tauri::AppHandle) {
+    let mut delay_secs = RESTART_INITIAL_BACKOFF_SECS;
+
+    for attempt in 1..=MAX_RESTART_ATTEMPTS {
+        if app_handle.state::<BackendState>().shutting_down.load(Ordering::SeqCst) {
+            println!("Auto-restart cancelled: shutdown in progress");
+            return;
+        }
+
+        let _ = app_handle.emit_all("backend-restarting", attempt);
+        println!("Auto-restart attempt {}/{} in {}s...", attempt, MAX_RESTART_ATTEMPTS, delay_secs);
+        std::thread::sleep(std::time::Duration::from_secs(delay_secs));
+
+        if let Some(child) = start_backend(&app_handle) {
+            *app_handle.state::<BackendState>().process.lock().unwrap() = Some(child);
+            println!("Backend auto-restart succeeded on attempt {}", attempt);
+            let _ = app_handle.emit_all("backend-restored", attempt);
+            return;
+        }
+
+        delay_secs = (delay_secs * 2).min(RESTART_MAX_BACKOFF_SECS);
+    }
+
+    eprintln!("Backend auto-restart gave up after {} attempts", MAX_RESTART_ATTEMPTS);
+    // The crashed child this whole retry loop started from is long dead;
+    // don't leave its stale handle in `process` for a later shutdown/
+    // restart to needlessly try to terminate.
+    *app_handle.state::<BackendState>().process.lock().unwrap() = None;
+}
 
 // Start Python backend
-fn start_backend() -> Option<Child> {
+fn start_backend(app_handle: &tauri::AppHandle) -> Option<Arc<SharedChild>> {
     // Get the current executable directory
     let exe_dir = match std::env::current_exe() {
         Ok(exe_path) => exe_path.parent().unwrap().to_path_buf(),
@@ -122,27 +501,66 @@ fn start_backend() -> Option<Child> {
     // Look for bundled vybe_app directory first
     let bundled_dir = exe_dir.join("vybe_app");
     
-    // Try different Python setups with priority order for reliability
-    let python_setups = if cfg!(target_os = "windows") {
+    let current_dir = std::env::current_dir().unwrap_or(exe_dir.clone());
+
+    // Bind an ephemeral port up front so this instance never collides with
+    // another Vybe window/instance already holding the default port. Keep
+    // the listener itself alive (rather than dropping it once we've read
+    // the port back out) to shrink the TOCTOU window where a second,
+    // concurrently-launching Vybe instance could grab the same port before
+    // our child has bound it: it's released with `drop(port_listener)`
+    // right before the `SharedChild::spawn` call it's guarding.
+    let mut port_listener = match std::net::TcpListener::bind("127.0.0.1:0") {
+        Ok(listener) => Some(listener),
+        Err(e) => {
+            eprintln!(
+                "Failed to bind an ephemeral port ({}); falling back to the hardcoded default {} with its collision risk",
+                e, DEFAULT_BACKEND_PORT
+            );
+            None
+        }
+    };
+    let port = match port_listener.as_ref().map(|listener| listener.local_addr()) {
+        Some(Ok(addr)) => addr.port(),
+        Some(Err(e)) => {
+            eprintln!(
+                "Failed to read back the bound ephemeral port ({}); falling back to the hardcoded default {} with its collision risk",
+                e, DEFAULT_BACKEND_PORT
+            );
+            port_listener = None;
+            DEFAULT_BACKEND_PORT
+        }
+        None => DEFAULT_BACKEND_PORT,
+    };
+    *app_handle.state::<BackendState>().port.lock().unwrap() = port;
+    println!("Allocated backend port: {}", port);
+
+    // Try different Python setups with priority order for reliability.
+    // On Windows the bundled venv always wins when present; system discovery
+    // (via `find_python`) is the reliable fallback instead of guessing a
+    // single hardcoded binary name. Other platforms have no bundled venv
+    // candidate and go straight to system discovery.
+    let mut python_setups = if cfg!(target_os = "windows") {
         vec![
             // 1. Try bundled Python environment (for portable installation)
-            (exe_dir.join("vybe-env-311-fixed").join("Scripts").join("python.exe"), exe_dir.clone()),
+            (exe_dir.join("vybe-env-311-fixed").join("Scripts").join("python.exe"), exe_dir.clone(), "bundled"),
             // 2. Try development environment (if running from project directory)
-            (std::env::current_dir().unwrap_or(exe_dir.clone()).join("vybe-env-311-fixed").join("Scripts").join("python.exe"), std::env::current_dir().unwrap_or(exe_dir.clone())),
-            // 3. Try system Python with bundled app directory
-            ("python.exe".to_string().into(), exe_dir.clone()),
-            // 4. Try system Python in current directory (development mode)
-            ("python.exe".to_string().into(), std::env::current_dir().unwrap_or(exe_dir.clone())),
+            (current_dir.join("vybe-env-311-fixed").join("Scripts").join("python.exe"), current_dir.clone(), "bundled"),
         ]
     } else {
-        vec![
-            // Unix-like systems
-            ("python3".to_string().into(), exe_dir.clone()),
-            ("python3".to_string().into(), std::env::current_dir().unwrap_or(exe_dir.clone())),
-        ]
+        vec![]
     };
-    
-    for (python_cmd, working_dir) in python_setups {
+
+    // 3. Discovered system Python, tried against both the bundled app
+    // directory and the current working directory (development mode).
+    if let Some(system_python) = find_python() {
+        python_setups.push((system_python.clone(), exe_dir.clone(), "system"));
+        python_setups.push((system_python, current_dir.clone(), "system"));
+    } else {
+        eprintln!("No system Python >= {}.{} found on PATH", MIN_PYTHON_VERSION.0, MIN_PYTHON_VERSION.1);
+    }
+
+    for (python_cmd, working_dir, mode) in python_setups {
         let python_str = python_cmd.to_string_lossy().to_string();
         let run_py_path = working_dir.join("run.py");
         
@@ -157,16 +575,76 @@ fn start_backend() -> Option<Child> {
         println!("Working directory: {}", working_dir.display());
         println!("run.py path: {}", run_py_path.display());
         
-        let backend_cmd = Command::new(&python_cmd)
+        // `SharedChild`'s public surface is only `spawn`/`id`/`wait`/
+        // `try_wait`/`kill` - unlike `std::process::Child` it never hands
+        // the piped `Command`'s stdio back to the caller, so `Stdio::piped()`
+        // plus `SharedChild::spawn` would leave us with no way to read the
+        // child's output. Create the pipes ourselves with `os_pipe` instead:
+        // we keep the read ends, and give the command the write ends, which
+        // get duplicated onto the child's stdout/stderr at spawn time.
+        let (stdout_reader, stdout_writer) = match os_pipe::pipe() {
+            Ok(pipe) => pipe,
+            Err(e) => {
+                eprintln!("Failed to create stdout pipe: {}", e);
+                continue;
+            }
+        };
+        let (stderr_reader, stderr_writer) = match os_pipe::pipe() {
+            Ok(pipe) => pipe,
+            Err(e) => {
+                eprintln!("Failed to create stderr pipe: {}", e);
+                continue;
+            }
+        };
+
+        let mut command = Command::new(&python_cmd);
+        command
             .arg("run.py")
             .current_dir(&working_dir)
             .env("VYBE_TEST_MODE", "true")  // Enable test mode for desktop app
             .env("VYBE_DESKTOP_MODE", "true")  // Indicate this is running from desktop app
-            .spawn();
-        
-        match backend_cmd {
+            .env("VYBE_PORT", port.to_string())  // Tell the backend which port to bind
+            .stdout(stdout_writer)
+            .stderr(stderr_writer);
+
+        // Give the child its own process group so `terminate_child` can
+        // target it alone with CTRL_BREAK_EVENT on Windows without also
+        // signaling us.
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            command.creation_flags(CREATE_NEW_PROCESS_GROUP);
+        }
+
+        // Release the ephemeral port right before the child gets a chance
+        // to bind it - only the attempt that actually reaches `spawn` needs
+        // it freed; earlier `continue`s above left it held.
+        drop(port_listener.take());
+
+        // Spawned via SharedChild so the supervisor thread below can
+        // `wait()` on it while commands still hold a handle to `kill()` it.
+        match SharedChild::spawn(&mut command) {
             Ok(child) => {
+                let child = Arc::new(child);
                 println!("Python backend started successfully with PID: {}", child.id());
+                let backend_state = app_handle.state::<BackendState>();
+                *backend_state.python_mode.lock().unwrap() = mode.to_string();
+
+                // Publish this child to `process` (and clear `shutting_down`)
+                // before `spawn_supervisor` below starts watching it: every
+                // caller of `start_backend` also assigns its return value to
+                // `process`, but only *after* this function has returned,
+                // which leaves a window where a child that crashes
+                // immediately isn't visible yet to the supervisor's
+                // `is_current` check and the crash goes unnoticed.
+                *backend_state.process.lock().unwrap() = Some(Arc::clone(&child));
+                backend_state.shutting_down.store(false, Ordering::SeqCst);
+
+                spawn_log_reader(stdout_reader, "stdout", app_handle.clone());
+                spawn_log_reader(stderr_reader, "stderr", app_handle.clone());
+
+                spawn_supervisor(app_handle.clone(), Arc::clone(&child));
+
                 return Some(child);
             }
             Err(e) => {
@@ -175,61 +653,112 @@ fn start_backend() -> Option<Child> {
             }
         }
     }
-    
+
+    eprintln!("No external Python interpreter worked; falling back to the bundled RustPython runtime...");
+    for working_dir in [exe_dir.clone(), current_dir.clone()] {
+        let run_py_path = working_dir.join("run.py");
+        if !run_py_path.exists() {
+            continue;
+        }
+        if run_rustpython_backend(&run_py_path, port) {
+            *app_handle.state::<BackendState>().python_mode.lock().unwrap() = "rustpython".to_string();
+            println!("Running {} via the embedded RustPython interpreter", run_py_path.display());
+            return None;
+        }
+    }
+
     eprintln!("All Python backend startup attempts failed!");
     eprintln!("Please ensure:");
     eprintln!("1. Python is installed and in PATH");
     eprintln!("2. Vybe application files are properly bundled");
     eprintln!("3. run.py exists in the application directory");
+    // Nothing is running; don't leave `python_mode` reporting whatever mode
+    // the last successful start (or the initial default) used.
+    *app_handle.state::<BackendState>().python_mode.lock().unwrap() = "none".to_string();
     None
 }
 
+// Run `run_py_path` in-process through the bundled RustPython interpreter,
+// on `port`. This is the last-resort fallback when no compatible external
+// CPython is installed; since it isn't a separate OS process it can't be
+// supervised, restarted or piped for logs the way the external-process path
+// is, and it may still lack C-extension modules the real backend depends
+// on. Returns true once the interpreter thread has been launched.
+fn run_rustpython_backend(run_py_path: &std::path::Path, port: u16) -> bool {
+    let run_py_path = run_py_path.to_path_buf();
+
+    let spawned = std::thread::Builder::new()
+        .name("rustpython-backend".to_string())
+        .spawn(move || {
+            // Mirror the env vars the external-process path sets via
+            // `.env()` on the Command - the embedded interpreter has no
+            // other way to learn which port/mode it's running under.
+            std::env::set_var("VYBE_TEST_MODE", "true");
+            std::env::set_var("VYBE_DESKTOP_MODE", "true");
+            std::env::set_var("VYBE_PORT", port.to_string());
+
+            let source = match std::fs::read_to_string(&run_py_path) {
+                Ok(source) => source,
+                Err(e) => {
+                    eprintln!("RustPython: failed to read {}: {}", run_py_path.display(), e);
+                    return;
+                }
+            };
+
+            // Register the native stdlib modules (os, socket, threading,
+            // ...) and the frozen pure-Python standard library, the same
+            // way the real `rustpython` binary initializes its VM - without
+            // this, `without_stdlib` leaves `import os` failing on line one
+            // of any real run.py.
+            let interpreter = rustpython_vm::Interpreter::with_init(Default::default(), |vm| {
+                vm.add_native_modules(rustpython_stdlib::get_module_inits());
+                vm.add_frozen(rustpython_pylib::FROZEN_STDLIB);
+            });
+            interpreter.enter(|vm| {
+                let scope = vm.new_scope_with_builtins();
+                let code = match vm.compile(
+                    &source,
+                    rustpython_vm::compiler::Mode::Exec,
+                    run_py_path.to_string_lossy().to_string(),
+                ) {
+                    Ok(code) => code,
+                    Err(e) => {
+                        eprintln!("RustPython: failed to compile {}: {:?}", run_py_path.display(), e);
+                        return;
+                    }
+                };
+                if let Err(e) = vm.run_code_obj(code, scope) {
+                    vm.print_exception(e);
+                }
+            });
+        });
+
+    spawned.is_ok()
+}
+
 // Shutdown Python backend
 fn shutdown_backend(backend_state: State<BackendState>) {
-    if let Some(mut process) = backend_state.process.lock().unwrap().take() {
+    backend_state.shutting_down.store(true, Ordering::SeqCst);
+
+    // Keep the handle in place (clone instead of take) while we wait on
+    // it, so a concurrent status check or the supervisor can still see it.
+    let process = backend_state.process.lock().unwrap().clone();
+    if let Some(process) = process {
         println!("Shutting down Python backend...");
-        
-        // Try graceful shutdown first
-        if let Err(_) = process.try_wait() {
-            // Process is still running, try to terminate gracefully
-            #[cfg(target_os = "windows")]
-            {
-                use std::process::Command;
-                // Send CTRL+C signal on Windows
-                let _ = Command::new("taskkill")
-                    .args(&["/F", "/T", "/PID", &process.id().to_string()])
-                    .output();
-            }
-            
-            #[cfg(not(target_os = "windows"))]
-            {
-                // Send SIGTERM on Unix-like systems
-                let _ = process.kill();
-            }
-            
-            // Wait up to 5 seconds for graceful shutdown
-            let start = std::time::Instant::now();
-            loop {
-                if let Ok(Some(_)) = process.try_wait() {
-                    println!("Python backend shut down gracefully");
-                    break;
-                }
-                if start.elapsed().as_secs() > 5 {
-                    println!("Forcing Python backend termination...");
-                    let _ = process.kill();
-                    let _ = process.wait();
-                    break;
-                }
-                std::thread::sleep(std::time::Duration::from_millis(100));
-            }
-        }
-        
+        terminate_child(&process, SHUTDOWN_TIMEOUT_MS);
+        *backend_state.process.lock().unwrap() = None;
         println!("Python backend shut down completed");
     }
 }
 
 fn main() {
-    let backend_state = BackendState { process: Mutex::new(None) };
+    let backend_state = BackendState {
+        process: Mutex::new(None),
+        logs: Mutex::new(VecDeque::with_capacity(LOG_BUFFER_CAPACITY)),
+        shutting_down: AtomicBool::new(false),
+        python_mode: Mutex::new("none".to_string()),
+        port: Mutex::new(DEFAULT_BACKEND_PORT),
+    };
 
     tauri::Builder::default()
         .manage(backend_state)
@@ -239,11 +768,14 @@ fn main() {
             check_backend_status,
             shutdown_app,
             get_backend_logs,
-            restart_backend
+            clear_backend_logs,
+            restart_backend,
+            get_backend_port
         ])
         .setup(|app| {
+            let app_handle = app.handle();
             let backend_state = app.state::<BackendState>();
-            *backend_state.process.lock().unwrap() = start_backend();
+            *backend_state.process.lock().unwrap() = start_backend(&app_handle);
 
             let main_window = app.get_window("main").unwrap();
 
@@ -276,4 +808,44 @@ fn main() {
         })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_plain_three_part_version() {
+        assert_eq!(parse_python_version("Python 3.11.4"), Some((3, 11)));
+    }
+
+    #[test]
+    fn parses_python_2() {
+        assert_eq!(parse_python_version("Python 2.7.18"), Some((2, 7)));
+    }
+
+    #[test]
+    fn trims_surrounding_whitespace() {
+        assert_eq!(parse_python_version("Python 3.11.4\n"), Some((3, 11)));
+    }
+
+    #[test]
+    fn rejects_missing_minor_component() {
+        assert_eq!(parse_python_version("Python 3"), None);
+    }
+
+    #[test]
+    fn rejects_output_without_the_python_prefix() {
+        assert_eq!(parse_python_version("command not found"), None);
+    }
+
+    #[test]
+    fn rejects_empty_output() {
+        assert_eq!(parse_python_version(""), None);
+    }
+
+    #[test]
+    fn rejects_non_numeric_components() {
+        assert_eq!(parse_python_version("Python a.b.c"), None);
+    }
 }
\ No newline at end of file